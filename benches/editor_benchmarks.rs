@@ -5,6 +5,32 @@ use editor::cursor::{Cursor, CursorId, Cursors};
 use editor::event::Event;
 use editor::state::EditorState;
 
+/// A multi-thousand-line markdown document, bundled at compile time, so
+/// the realistic-document benches below exercise the edit patterns a real
+/// file produces instead of only synthetic `"a".repeat(n)` inputs.
+const LARGE_DOCUMENT: &str = include_str!("fixtures/large_document.md");
+
+/// A small deterministic PRNG (xorshift64) so bench position sequences
+/// are reproducible across runs without pulling in a `rand` dependency.
+struct Xorshift64(u64);
+
+impl Xorshift64 {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn next_below(&mut self, bound: usize) -> usize {
+        (self.next_u64() as usize) % bound.max(1)
+    }
+}
+
 /// Benchmark buffer insert operations
 fn bench_buffer_insert(c: &mut Criterion) {
     let mut group = c.benchmark_group("buffer_insert");
@@ -230,6 +256,120 @@ fn bench_editing_workflow(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark inserting a short string at random positions across a real
+/// multi-thousand-line document, instead of always inserting at byte 0 of
+/// a synthetic string.
+fn bench_large_document_random_insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("large_document_random_insert");
+    group.throughput(Throughput::Bytes(LARGE_DOCUMENT.len() as u64));
+
+    group.bench_function("random_position", |b| {
+        b.iter(|| {
+            let mut buffer = Buffer::from_str(LARGE_DOCUMENT);
+            let mut rng = Xorshift64::new(42);
+            for _ in 0..100 {
+                let position = rng.next_below(buffer.len());
+                buffer.insert(black_box(position), black_box("edit "));
+            }
+        });
+    });
+
+    group.finish();
+}
+
+/// Benchmark deleting small ranges at random positions across a real
+/// document. This is the pattern `bench_buffer_delete`'s
+/// `buffer.clone()` + `delete(0..size)` hid: deleting from the middle of
+/// a large, already-loaded document rather than clearing it wholesale.
+fn bench_large_document_random_delete(c: &mut Criterion) {
+    let mut group = c.benchmark_group("large_document_random_delete");
+    group.throughput(Throughput::Bytes(LARGE_DOCUMENT.len() as u64));
+
+    group.bench_function("random_position", |b| {
+        b.iter(|| {
+            let mut buffer = Buffer::from_str(LARGE_DOCUMENT);
+            let mut rng = Xorshift64::new(7);
+            for _ in 0..100 {
+                let len = buffer.len();
+                if len < 10 {
+                    break;
+                }
+                let start = rng.next_below(len - 5);
+                buffer.delete(black_box(start..start + 5));
+            }
+        });
+    });
+
+    group.finish();
+}
+
+/// Benchmark a full-document sweep of `byte_to_line`/`line_to_byte`,
+/// which is what a "jump to line" or "show current line" status bar does
+/// on every render.
+fn bench_large_document_position_sweep(c: &mut Criterion) {
+    let mut group = c.benchmark_group("large_document_position_sweep");
+    let buffer = Buffer::from_str(LARGE_DOCUMENT);
+    let line_count = buffer.byte_to_line(buffer.len());
+
+    group.bench_function("byte_to_line_sweep", |b| {
+        b.iter(|| {
+            let mut byte = 0;
+            while byte < buffer.len() {
+                black_box(buffer.byte_to_line(byte));
+                byte += 97; // a prime-ish stride so we sample across lines
+            }
+        });
+    });
+
+    group.bench_function("line_to_byte_sweep", |b| {
+        b.iter(|| {
+            for line in 0..line_count {
+                black_box(buffer.line_to_byte(line));
+            }
+        });
+    });
+
+    group.finish();
+}
+
+/// Benchmark editing a real document with many cursors active at once,
+/// mirroring a multi-cursor rename across the whole file.
+fn bench_large_document_multi_cursor_edit(c: &mut Criterion) {
+    let mut group = c.benchmark_group("large_document_multi_cursor_edit");
+
+    for cursor_count in [10, 50, 200] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(cursor_count),
+            &cursor_count,
+            |b, &cursor_count| {
+                b.iter(|| {
+                    let buffer = Buffer::from_str(LARGE_DOCUMENT);
+                    let mut state = EditorState::new(buffer, 80, 24);
+                    let mut rng = Xorshift64::new(123);
+
+                    let mut cursor_ids = Vec::with_capacity(cursor_count);
+                    for _ in 0..cursor_count {
+                        let position = rng.next_below(state.buffer.len());
+                        cursor_ids.push(state.cursors.add(Cursor::new(position)));
+                    }
+
+                    for &cursor_id in &cursor_ids {
+                        let position = state.cursors.get(cursor_id).unwrap().position;
+                        let event = Event::Insert {
+                            position,
+                            text: "x".to_string(),
+                            cursor_id,
+                        };
+                        state.apply(black_box(&event));
+                    }
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_buffer_insert,
@@ -241,5 +381,9 @@ criterion_group!(
     bench_event_application_insert,
     bench_event_application_delete,
     bench_editing_workflow,
+    bench_large_document_random_insert,
+    bench_large_document_random_delete,
+    bench_large_document_position_sweep,
+    bench_large_document_multi_cursor_edit,
 );
 criterion_main!(benches);
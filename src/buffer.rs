@@ -0,0 +1,131 @@
+use std::ops::Range;
+
+use crate::piece_table::PieceTable;
+
+/// The editor's text storage for a single document, backed by a
+/// [`PieceTable`] instead of one flat `String`.
+///
+/// Line start offsets are cached so `line_to_byte`/`byte_to_line` don't
+/// rescan the whole document on every call. Unlike the original
+/// implementation, the cache is *patched* after each edit instead of
+/// rebuilt from scratch: the text storage itself scales with the size of
+/// the edit (see [`PieceTable`]), but the line cache still has to shift
+/// every line start at or after the edit point, so patching it costs
+/// O(lines after the edit point), not O(document length) but not O(edit
+/// size) either.
+#[derive(Debug, Clone)]
+pub struct Buffer {
+    pieces: PieceTable,
+    line_starts: Vec<usize>,
+}
+
+impl Buffer {
+    pub fn new() -> Self {
+        Self::from_str("")
+    }
+
+    // Kept as `from_str` (not `FromStr::from_str`) for API stability with
+    // existing callers; the lint would want a trait impl instead.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(text: &str) -> Self {
+        Self { pieces: PieceTable::from_text(text), line_starts: initial_line_starts(text) }
+    }
+
+    pub fn insert(&mut self, position: usize, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        self.pieces.insert(position, text);
+        self.patch_line_starts_after_insert(position, text);
+    }
+
+    pub fn delete(&mut self, range: Range<usize>) {
+        if range.start == range.end {
+            return;
+        }
+        self.pieces.delete(range.clone());
+        self.patch_line_starts_after_delete(range);
+    }
+
+    pub fn len(&self) -> usize {
+        self.pieces.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pieces.is_empty()
+    }
+
+    /// Byte offset of the start of `line` (0-indexed), clamped to the end
+    /// of the buffer for out-of-range lines.
+    pub fn line_to_byte(&self, line: usize) -> usize {
+        self.line_starts.get(line).copied().unwrap_or(self.pieces.len())
+    }
+
+    /// Line (0-indexed) containing byte offset `byte`.
+    pub fn byte_to_line(&self, byte: usize) -> usize {
+        match self.line_starts.binary_search(&byte) {
+            Ok(line) => line,
+            Err(insertion_point) => insertion_point.saturating_sub(1),
+        }
+    }
+
+    fn patch_line_starts_after_insert(&mut self, position: usize, text: &str) {
+        let line = self.byte_to_line(position);
+
+        for start in &mut self.line_starts[line + 1..] {
+            *start += text.len();
+        }
+
+        let new_starts = newline_starts_within(text, position);
+        self.line_starts.splice(line + 1..line + 1, new_starts);
+    }
+
+    fn patch_line_starts_after_delete(&mut self, range: Range<usize>) {
+        let len = range.end - range.start;
+        // Line 0 always starts at byte 0 and is never dropped; every other
+        // line start after `range.start` up to and including `range.end`
+        // disappears, since the newline that opened it was deleted and
+        // whatever followed the deletion now continues the previous line.
+        let mut is_first = true;
+        self.line_starts.retain(|&start| {
+            let keep = is_first || start <= range.start || start > range.end;
+            is_first = false;
+            keep
+        });
+        for start in &mut self.line_starts {
+            if *start >= range.end {
+                *start -= len;
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Buffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.pieces.text())
+    }
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Byte offsets of the start of every line in `text`, including the
+/// implicit line 0 at offset 0.
+fn initial_line_starts(text: &str) -> Vec<usize> {
+    let mut starts = vec![0];
+    starts.extend(newline_starts_within(text, 0));
+    starts
+}
+
+/// Byte offsets, within `text` shifted by `base`, of the start of every
+/// new line `text` introduces (i.e. one past every `\n` it contains).
+fn newline_starts_within(text: &str, base: usize) -> Vec<usize> {
+    text.bytes()
+        .enumerate()
+        .filter(|&(_, b)| b == b'\n')
+        .map(|(i, _)| base + i + 1)
+        .collect()
+}
@@ -0,0 +1,324 @@
+//! RGA-style sequence CRDT for real-time collaborative editing.
+//!
+//! `Event::{Insert, Delete, MoveCursor}` are position-based: replaying
+//! them out of order (or concurrently from two peers) can produce
+//! different final text. This module gives every inserted character a
+//! globally unique [`ElementId`] and an explicit left origin, so applying
+//! the same set of remote ops in any order converges to the same visible
+//! text — the core idea behind Replicated Growable Arrays.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::cursor::{CursorId, Cursors};
+use crate::event::Event;
+
+/// Globally unique identifier for one inserted character: the site that
+/// created it plus a per-site monotonic counter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ElementId {
+    pub site_id: u64,
+    pub counter: u64,
+}
+
+impl ElementId {
+    /// Concurrent inserts sharing a left origin are ordered deterministically:
+    /// ascending by counter, tie-broken by ascending site_id (the lower ID
+    /// wins), so every replica picks the same order without coordination.
+    /// `resolve_insert_position` gets there by keeping siblings sorted in
+    /// *descending* `ordering_key()` order — `Reverse` inverts both fields,
+    /// so descending-key order is ascending-(counter, site_id) order.
+    fn ordering_key(&self) -> (std::cmp::Reverse<u64>, std::cmp::Reverse<u64>) {
+        (std::cmp::Reverse(self.counter), std::cmp::Reverse(self.site_id))
+    }
+}
+
+/// One character in the RGA sequence. Deletes never remove an element;
+/// they flag it as a tombstone so a delete that arrives before or after a
+/// concurrent insert at the same position still produces identical text.
+#[derive(Debug, Clone)]
+struct Element {
+    id: ElementId,
+    left_origin: Option<ElementId>,
+    ch: char,
+    tombstone: bool,
+}
+
+/// A CRDT-backed remote edit, mirroring `Event` but addressed by element
+/// ID instead of byte position so it's commutative and idempotent.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RemoteOp {
+    Insert { id: ElementId, left_origin: Option<ElementId>, ch: char },
+    Delete { id: ElementId },
+}
+
+/// The RGA sequence plus the local site's counter, sitting alongside an
+/// `EditorState`'s `Buffer` and `Cursors`.
+#[derive(Debug, Clone)]
+pub struct Crdt {
+    site_id: u64,
+    counter: u64,
+    elements: Vec<Element>,
+    index_by_id: HashMap<ElementId, usize>,
+    applied: HashSet<ElementId>,
+    /// IDs tombstoned by a delete that arrived before its matching insert,
+    /// applied the moment that insert lands so delete/insert reordering
+    /// can't resurrect a deleted character.
+    pending_deletes: HashSet<ElementId>,
+    /// Inserts buffered because their `left_origin` hasn't arrived yet,
+    /// keyed by that missing origin and released (recursively, since a
+    /// released insert can itself unblock further descendants) the moment
+    /// it lands. This is what makes `apply_remote` order-independent for
+    /// concurrent inserts, not just causally-ordered ones.
+    pending_inserts: HashMap<ElementId, Vec<RemoteOp>>,
+    /// IDs currently sitting in `pending_inserts`, so a duplicate delivery
+    /// of an already-buffered insert doesn't queue it twice.
+    pending_insert_ids: HashSet<ElementId>,
+}
+
+impl Crdt {
+    pub fn new(site_id: u64) -> Self {
+        Self {
+            site_id,
+            counter: 0,
+            elements: Vec::new(),
+            index_by_id: HashMap::new(),
+            applied: HashSet::new(),
+            pending_deletes: HashSet::new(),
+            pending_inserts: HashMap::new(),
+            pending_insert_ids: HashSet::new(),
+        }
+    }
+
+    /// Visible text: tombstones are skipped, so deleted characters never
+    /// reappear regardless of when their delete was applied.
+    pub fn text(&self) -> String {
+        self.elements.iter().filter(|e| !e.tombstone).map(|e| e.ch).collect()
+    }
+
+    /// Generate the `RemoteOp`s for a local insert of `text` at visible
+    /// byte offset `position`, advancing this site's counter.
+    pub fn local_insert(&mut self, position: usize, text: &str) -> Vec<RemoteOp> {
+        let mut left_origin = self.visible_element_id_before(position);
+        let mut ops = Vec::with_capacity(text.chars().count());
+
+        for ch in text.chars() {
+            self.counter += 1;
+            let id = ElementId { site_id: self.site_id, counter: self.counter };
+            let op = RemoteOp::Insert { id, left_origin, ch };
+            self.apply_remote(op.clone());
+            ops.push(op);
+            left_origin = Some(id);
+        }
+
+        ops
+    }
+
+    /// Generate the `RemoteOp`s that tombstone the visible byte range
+    /// `start..end`.
+    pub fn local_delete(&mut self, start: usize, end: usize) -> Vec<RemoteOp> {
+        let ids: Vec<ElementId> = self
+            .visible_indices(start, end)
+            .into_iter()
+            .map(|idx| self.elements[idx].id)
+            .collect();
+
+        ids.into_iter()
+            .map(|id| {
+                let op = RemoteOp::Delete { id };
+                self.apply_remote(op.clone());
+                op
+            })
+            .collect()
+    }
+
+    /// Apply a remote (or local) op. Idempotent: replaying an op whose ID
+    /// was already applied (or is already buffered, pending its origin)
+    /// is a no-op, so duplicate delivery from an unreliable transport
+    /// can't corrupt the sequence. An insert whose `left_origin` hasn't
+    /// arrived yet is buffered in `pending_inserts` rather than treated as
+    /// anchored at the start of the document, so inserts — like deletes —
+    /// tolerate arriving in any order, not just causal order.
+    pub fn apply_remote(&mut self, op: RemoteOp) {
+        match op {
+            RemoteOp::Insert { id, left_origin, ch } => {
+                if self.applied.contains(&id) || self.pending_insert_ids.contains(&id) {
+                    return;
+                }
+                if let Some(origin) = left_origin {
+                    if !self.applied.contains(&origin) {
+                        self.pending_insert_ids.insert(id);
+                        self.pending_inserts.entry(origin).or_default().push(RemoteOp::Insert {
+                            id,
+                            left_origin,
+                            ch,
+                        });
+                        return;
+                    }
+                }
+
+                self.applied.insert(id);
+                let tombstone = self.pending_deletes.remove(&id);
+                let insert_at = self.resolve_insert_position(left_origin, id);
+                self.elements.insert(insert_at, Element { id, left_origin, ch, tombstone });
+                self.reindex_from(insert_at);
+                self.release_pending_inserts(id);
+            }
+            RemoteOp::Delete { id } => {
+                if let Some(&idx) = self.index_by_id.get(&id) {
+                    self.elements[idx].tombstone = true;
+                } else {
+                    self.pending_deletes.insert(id);
+                }
+            }
+        }
+    }
+
+    /// Apply every insert that was waiting on `id` to land, recursively,
+    /// since releasing one can unblock descendants anchored on it.
+    fn release_pending_inserts(&mut self, id: ElementId) {
+        if let Some(children) = self.pending_inserts.remove(&id) {
+            for child in children {
+                if let RemoteOp::Insert { id: child_id, .. } = &child {
+                    self.pending_insert_ids.remove(child_id);
+                }
+                self.apply_remote(child);
+            }
+        }
+    }
+
+    /// Rebase every cursor's visible-offset position against element IDs
+    /// after applying a batch of remote ops, so local selections survive
+    /// concurrent remote edits instead of drifting to the wrong offset.
+    pub fn rebase_cursors(&self, cursors: &mut Cursors, before: &[(CursorId, Option<ElementId>)]) {
+        for &(cursor_id, anchor_id) in before {
+            if let Some(cursor) = cursors.get_mut(cursor_id) {
+                cursor.position = match anchor_id {
+                    // The cursor sits right after its anchor element, so
+                    // its visible byte offset is the anchor's byte offset
+                    // plus the anchor character's own width (zero once the
+                    // anchor has been deleted).
+                    Some(id) => self.visible_offset_of(id).unwrap_or(cursor.position),
+                    None => 0,
+                };
+            }
+        }
+    }
+
+    /// The element ID a cursor at visible offset `position` is currently
+    /// sitting after, for use as the anchor passed back into
+    /// `rebase_cursors` once remote ops land.
+    pub fn anchor_for(&self, position: usize) -> Option<ElementId> {
+        self.visible_element_id_before(position)
+    }
+
+    /// Visible byte offset immediately *after* `id`'s character, counting
+    /// only non-tombstoned elements up to and including it (a deleted
+    /// element adds no width, so a cursor anchored on it lands where that
+    /// character used to be).
+    fn visible_offset_of(&self, id: ElementId) -> Option<usize> {
+        let idx = *self.index_by_id.get(&id)?;
+        let upto: usize =
+            self.elements[..=idx].iter().filter(|e| !e.tombstone).map(|e| e.ch.len_utf8()).sum();
+        Some(upto)
+    }
+
+    /// The element a cursor at visible byte offset `position` is sitting
+    /// right after, found by accumulating each visible character's
+    /// `len_utf8` rather than counting characters, since `position` is a
+    /// byte offset into the same space as `Buffer::insert`/`delete`.
+    fn visible_element_id_before(&self, position: usize) -> Option<ElementId> {
+        if position == 0 {
+            return None;
+        }
+        let mut offset = 0;
+        let mut last = None;
+        for element in self.elements.iter().filter(|e| !e.tombstone) {
+            offset += element.ch.len_utf8();
+            last = Some(element.id);
+            if offset >= position {
+                break;
+            }
+        }
+        last
+    }
+
+    /// Indices (into `self.elements`, not the visible sequence) of every
+    /// visible element whose byte range overlaps `start..end`.
+    fn visible_indices(&self, start: usize, end: usize) -> Vec<usize> {
+        let mut offset = 0;
+        let mut indices = Vec::new();
+        for (idx, element) in self.elements.iter().enumerate() {
+            if element.tombstone {
+                continue;
+            }
+            let char_start = offset;
+            offset += element.ch.len_utf8();
+            if char_start >= end {
+                break;
+            }
+            if offset > start && char_start < end {
+                indices.push(idx);
+            }
+        }
+        indices
+    }
+
+    /// Find where a new element belongs: right after its left origin, then
+    /// scanning forward over every element already anchored at or after
+    /// that same origin (not just direct siblings — their descendants too,
+    /// since those were themselves inserted "under" a sibling and must
+    /// move together with it) until reaching one that's either anchored
+    /// strictly before our origin, or a true sibling that sorts after us.
+    /// This is what makes two replicas converge regardless of the order
+    /// concurrent inserts arrive in, even when their subtrees interleave.
+    fn resolve_insert_position(&self, left_origin: Option<ElementId>, id: ElementId) -> usize {
+        let origin_pos = self.position_of(left_origin);
+        let mut pos = origin_pos.map(|p| p + 1).unwrap_or(0);
+
+        while let Some(sibling) = self.elements.get(pos) {
+            let sibling_origin_pos = self.position_of(sibling.left_origin);
+
+            match sibling_origin_pos.cmp(&origin_pos) {
+                // `sibling` is anchored strictly before our own origin, so
+                // it (and everything after it) falls outside our conflict
+                // zone — stop here.
+                std::cmp::Ordering::Less => break,
+                // A true sibling: same left origin, ordered deterministically.
+                std::cmp::Ordering::Equal => {
+                    if sibling.id.ordering_key() <= id.ordering_key() {
+                        break;
+                    }
+                    pos += 1;
+                }
+                // `sibling` is anchored after our origin's position —
+                // it's nested under a sibling inserted after our origin, so
+                // skip past its whole subtree.
+                std::cmp::Ordering::Greater => pos += 1,
+            }
+        }
+
+        pos
+    }
+
+    /// Current index of `id` in the element list, or `None` for the
+    /// virtual "start of document" origin.
+    fn position_of(&self, id: Option<ElementId>) -> Option<usize> {
+        id.and_then(|id| self.index_by_id.get(&id).copied())
+    }
+
+    fn reindex_from(&mut self, from: usize) {
+        for (idx, element) in self.elements.iter().enumerate().skip(from) {
+            self.index_by_id.insert(element.id, idx);
+        }
+    }
+}
+
+/// Translate a local `Event` into the `RemoteOp`s that reproduce it
+/// elsewhere, without needing the full `EditorState`.
+pub fn ops_for_event(crdt: &mut Crdt, event: &Event) -> Vec<RemoteOp> {
+    match event {
+        Event::Insert { position, text, .. } => crdt.local_insert(*position, text),
+        Event::Delete { range, .. } => crdt.local_delete(range.start, range.end),
+        Event::MoveCursor { .. } => Vec::new(),
+    }
+}
@@ -0,0 +1,106 @@
+use std::ops::Range;
+
+/// Stable identifier for a cursor, independent of its position or index
+/// in `Cursors`, so events can reference "the cursor that typed this"
+/// even after other cursors are added or removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CursorId(pub usize);
+
+/// A single insertion point, with an optional selection anchor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor {
+    pub position: usize,
+    pub anchor: Option<usize>,
+}
+
+impl Cursor {
+    pub fn new(position: usize) -> Self {
+        Self { position, anchor: None }
+    }
+}
+
+/// The full set of cursors for an `EditorState`, supporting multi-cursor
+/// editing. The first cursor added is the primary cursor.
+#[derive(Debug, Clone)]
+pub struct Cursors {
+    ids: Vec<CursorId>,
+    cursors: Vec<Cursor>,
+    next_id: usize,
+}
+
+impl Cursors {
+    pub fn new() -> Self {
+        let mut cursors = Self { ids: Vec::new(), cursors: Vec::new(), next_id: 0 };
+        cursors.add(Cursor::new(0));
+        cursors
+    }
+
+    pub fn add(&mut self, cursor: Cursor) -> CursorId {
+        let id = CursorId(self.next_id);
+        self.next_id += 1;
+        self.ids.push(id);
+        self.cursors.push(cursor);
+        id
+    }
+
+    pub fn primary(&self) -> &Cursor {
+        &self.cursors[0]
+    }
+
+    pub fn primary_id(&self) -> CursorId {
+        self.ids[0]
+    }
+
+    pub fn get(&self, id: CursorId) -> Option<&Cursor> {
+        self.ids.iter().position(|&i| i == id).map(|idx| &self.cursors[idx])
+    }
+
+    pub fn get_mut(&mut self, id: CursorId) -> Option<&mut Cursor> {
+        let idx = self.ids.iter().position(|&i| i == id)?;
+        Some(&mut self.cursors[idx])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (CursorId, &Cursor)> {
+        self.ids.iter().copied().zip(self.cursors.iter())
+    }
+
+    /// Shift every cursor at or after `position` forward by `len` bytes.
+    pub fn adjust_after_insert(&mut self, position: usize, len: usize) {
+        for cursor in &mut self.cursors {
+            if cursor.position >= position {
+                cursor.position += len;
+            }
+            if let Some(anchor) = cursor.anchor.as_mut().filter(|a| **a >= position) {
+                *anchor += len;
+            }
+        }
+    }
+
+    /// Shift cursors after a deleted `range`, clamping any cursor inside
+    /// the range to its start.
+    pub fn adjust_after_delete(&mut self, range: Range<usize>) {
+        let len = range.end - range.start;
+        for cursor in &mut self.cursors {
+            cursor.position = adjust_position_after_delete(cursor.position, &range, len);
+            if let Some(anchor) = cursor.anchor.as_mut() {
+                *anchor = adjust_position_after_delete(*anchor, &range, len);
+            }
+        }
+    }
+}
+
+fn adjust_position_after_delete(position: usize, range: &Range<usize>, len: usize) -> usize {
+    if position >= range.end {
+        position - len
+    } else if position >= range.start {
+        range.start
+    } else {
+        position
+    }
+}
+
+impl Default for Cursors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
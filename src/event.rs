@@ -0,0 +1,13 @@
+use std::ops::Range;
+
+use crate::cursor::CursorId;
+
+/// An editor edit, recorded so the editor stays event-sourced: replaying
+/// a sequence of `Event`s against a fresh `EditorState` reproduces the
+/// same buffer and cursor state.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Insert { position: usize, text: String, cursor_id: CursorId },
+    Delete { range: Range<usize>, deleted_text: String, cursor_id: CursorId },
+    MoveCursor { cursor_id: CursorId, position: usize, anchor: Option<usize> },
+}
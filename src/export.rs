@@ -0,0 +1,113 @@
+//! HTML export of a buffer through the markdown event stream.
+//!
+//! Reuses [`crate::markdown::parser::MarkdownParser`] rather than
+//! re-walking the source text, so export stays in lockstep with however
+//! compose mode styles headings, lists, code blocks and inline marks.
+
+use crate::markdown::parser::MarkdownParser;
+use crate::markdown::{MarkdownEvent, MarkdownOptions, Tag};
+use crate::state::EditorState;
+
+/// Render `text` as a bare HTML fragment (no `<html>`/`<head>` wrapper).
+pub fn to_html_fragment(text: &str, options: MarkdownOptions) -> String {
+    let events = MarkdownParser::new(text, options).parse();
+    let mut html = String::new();
+
+    for spanned in &events {
+        match &spanned.event {
+            MarkdownEvent::Start(tag) => html.push_str(&open_tag(tag)),
+            MarkdownEvent::End(tag) => html.push_str(&close_tag(tag)),
+            MarkdownEvent::Text(text) => html.push_str(&escape_html(text)),
+            MarkdownEvent::InlineCode(text) => {
+                html.push_str("<code>");
+                html.push_str(&escape_html(text));
+                html.push_str("</code>");
+            }
+            MarkdownEvent::SoftBreak => html.push(' '),
+            MarkdownEvent::HardBreak => html.push_str("<br/>\n"),
+            MarkdownEvent::TaskListMarker(checked) => {
+                html.push_str(&format!(
+                    "<input type=\"checkbox\" disabled{} /> ",
+                    if *checked { " checked" } else { "" }
+                ));
+            }
+        }
+    }
+
+    html
+}
+
+/// Render `text` as a self-contained HTML document with inlined CSS for
+/// headings, code blocks, task-list checkboxes and block quotes.
+pub fn to_html_document(title: &str, text: &str, options: MarkdownOptions) -> String {
+    let fragment = to_html_fragment(text, options);
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n{css}\n</style>\n</head>\n<body>\n{fragment}\n</body>\n</html>\n",
+        title = escape_html(title),
+        css = INLINE_CSS,
+        fragment = fragment,
+    )
+}
+
+const INLINE_CSS: &str = r#"
+body { font-family: sans-serif; max-width: 42rem; margin: 2rem auto; line-height: 1.5; }
+h1, h2, h3, h4, h5, h6 { font-weight: 600; }
+blockquote { border-left: 3px solid #ccc; margin-left: 0; padding-left: 1rem; color: #555; }
+pre code { display: block; background: #f5f5f5; padding: 1rem; overflow-x: auto; }
+input[type="checkbox"] { margin-right: 0.5em; vertical-align: middle; }
+del { opacity: 0.6; }
+"#;
+
+fn open_tag(tag: &Tag) -> String {
+    match tag {
+        Tag::Heading(level) => format!("<h{level}>"),
+        Tag::List(Some(_)) => "<ol>".to_string(),
+        Tag::List(None) => "<ul>".to_string(),
+        Tag::Item => "<li>".to_string(),
+        Tag::BlockQuote => "<blockquote>".to_string(),
+        Tag::CodeBlock(language) => match language {
+            Some(lang) => format!("<pre><code class=\"language-{lang}\">"),
+            None => "<pre><code>".to_string(),
+        },
+        Tag::Emphasis => "<em>".to_string(),
+        Tag::Strong => "<strong>".to_string(),
+        Tag::Strikethrough => "<del>".to_string(),
+        Tag::Link { dest } => format!("<a href=\"{}\">", escape_html(dest)),
+    }
+}
+
+fn close_tag(tag: &Tag) -> String {
+    match tag {
+        Tag::Heading(level) => format!("</h{level}>"),
+        Tag::List(Some(_)) => "</ol>".to_string(),
+        Tag::List(None) => "</ul>".to_string(),
+        Tag::Item => "</li>".to_string(),
+        Tag::BlockQuote => "</blockquote>".to_string(),
+        Tag::CodeBlock(_) => "</code></pre>".to_string(),
+        Tag::Emphasis => "</em>".to_string(),
+        Tag::Strong => "</strong>".to_string(),
+        Tag::Strikethrough => "</del>".to_string(),
+        Tag::Link { .. } => "</a>".to_string(),
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl EditorState {
+    /// Export this document's buffer as an HTML fragment, for headless
+    /// callers like `EditorTestHarness`. No command-palette action calls
+    /// it.
+    pub fn export_html_fragment(&self, options: MarkdownOptions) -> String {
+        to_html_fragment(&self.buffer.to_string(), options)
+    }
+
+    /// Export this document's buffer as a self-contained HTML document.
+    pub fn export_html_document(&self, title: &str, options: MarkdownOptions) -> String {
+        to_html_document(title, &self.buffer.to_string(), options)
+    }
+}
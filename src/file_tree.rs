@@ -0,0 +1,171 @@
+//! Project file-tree explorer, a first-class subsystem alongside
+//! [`EditorState`] rather than a feature bolted onto the buffer.
+//!
+//! The tree lazily reads directory entries on expand, keeps its own
+//! selection/scroll state independent of buffer cursors, and routes
+//! "open selected" through `EditorState::open_file` — the same path used
+//! when opening a file from the command palette.
+
+use std::collections::BTreeSet;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::state::EditorState;
+
+/// Default width, in columns, of the gutter the tree renders into. Kept
+/// small relative to the 80×24-style viewports `EditorState::new` is
+/// typically sized with, so the buffer still gets most of the width.
+pub const DEFAULT_GUTTER_WIDTH: usize = 24;
+
+/// One row of the rendered tree: a path and how deep it is nested, so the
+/// renderer can indent it without re-walking the hierarchy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileTreeEntry {
+    pub path: PathBuf,
+    pub depth: usize,
+    pub is_dir: bool,
+}
+
+/// A navigable, lazily-populated file-tree explorer rooted at a
+/// directory. Directories are only read when expanded, so opening a
+/// large project doesn't walk the whole tree up front.
+#[derive(Debug, Clone)]
+pub struct FileTree {
+    root: PathBuf,
+    expanded: BTreeSet<PathBuf>,
+    entries: Vec<FileTreeEntry>,
+    selected: usize,
+    scroll_offset: usize,
+}
+
+impl FileTree {
+    /// Create a tree rooted at `root`, with the root itself expanded so
+    /// its immediate children are visible right away.
+    pub fn new(root: impl Into<PathBuf>) -> io::Result<Self> {
+        let root = root.into();
+        let mut tree = Self {
+            root: root.clone(),
+            expanded: BTreeSet::new(),
+            entries: Vec::new(),
+            selected: 0,
+            scroll_offset: 0,
+        };
+        tree.expanded.insert(root);
+        tree.refresh()?;
+        Ok(tree)
+    }
+
+    /// Re-read every expanded directory under the root, e.g. after the
+    /// filesystem changes under a watched root. Preserves the current
+    /// selection's path where possible instead of resetting to the top.
+    pub fn refresh(&mut self) -> io::Result<()> {
+        let selected_path = self.selected_entry().map(|e| e.path.clone());
+        self.entries = build_entries(&self.root, 0, &self.expanded)?;
+        self.selected = selected_path
+            .and_then(|path| self.entries.iter().position(|e| e.path == path))
+            .unwrap_or(0)
+            .min(self.entries.len().saturating_sub(1));
+        Ok(())
+    }
+
+    pub fn entries(&self) -> &[FileTreeEntry] {
+        &self.entries
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected_entry(&self) -> Option<&FileTreeEntry> {
+        self.entries.get(self.selected)
+    }
+
+    pub fn scroll_offset(&self) -> usize {
+        self.scroll_offset
+    }
+
+    /// Columns left for the buffer viewport once this tree's gutter is
+    /// reserved out of `total_width` (e.g. `EditorState::width`).
+    pub fn buffer_viewport_width(&self, total_width: usize) -> usize {
+        total_width.saturating_sub(DEFAULT_GUTTER_WIDTH)
+    }
+
+    /// Move the selection down one row, scrolling the viewport of height
+    /// `viewport_height` if needed.
+    pub fn select_next(&mut self, viewport_height: usize) {
+        if self.selected + 1 < self.entries.len() {
+            self.selected += 1;
+        }
+        self.scroll_into_view(viewport_height);
+    }
+
+    /// Move the selection up one row, scrolling the viewport if needed.
+    pub fn select_previous(&mut self, viewport_height: usize) {
+        self.selected = self.selected.saturating_sub(1);
+        self.scroll_into_view(viewport_height);
+    }
+
+    /// Toggle expand/collapse of the selected directory, leaving files
+    /// untouched.
+    pub fn toggle_selected(&mut self) -> io::Result<()> {
+        let Some(entry) = self.selected_entry().cloned() else { return Ok(()) };
+        if !entry.is_dir {
+            return Ok(());
+        }
+        if !self.expanded.remove(&entry.path) {
+            self.expanded.insert(entry.path);
+        }
+        self.refresh()
+    }
+
+    /// Open the selected file through `EditorState::open_file` — the same
+    /// path `EditorTestHarness::open_file` drives in the markdown tests —
+    /// or toggle expand/collapse if a directory is selected.
+    pub fn open_selected(&mut self, state: &mut EditorState) -> io::Result<()> {
+        let Some(entry) = self.selected_entry().cloned() else { return Ok(()) };
+        if entry.is_dir {
+            self.toggle_selected()
+        } else {
+            state.open_file(&entry.path)
+        }
+    }
+
+    fn scroll_into_view(&mut self, viewport_height: usize) {
+        if viewport_height == 0 {
+            return;
+        }
+        if self.selected < self.scroll_offset {
+            self.scroll_offset = self.selected;
+        } else if self.selected >= self.scroll_offset + viewport_height {
+            self.scroll_offset = self.selected + 1 - viewport_height;
+        }
+    }
+}
+
+fn build_entries(
+    root: &Path,
+    depth: usize,
+    expanded: &BTreeSet<PathBuf>,
+) -> io::Result<Vec<FileTreeEntry>> {
+    let mut children: Vec<(PathBuf, bool)> = std::fs::read_dir(root)?
+        .map(|entry| {
+            let entry = entry?;
+            Ok((entry.path(), entry.file_type()?.is_dir()))
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    children.sort_by(|a, b| match (a.1, b.1) {
+        (true, false) => std::cmp::Ordering::Less,
+        (false, true) => std::cmp::Ordering::Greater,
+        _ => a.0.cmp(&b.0),
+    });
+
+    let mut entries = Vec::new();
+    for (path, is_dir) in children {
+        let expanded_here = is_dir && expanded.contains(&path);
+        entries.push(FileTreeEntry { path: path.clone(), depth, is_dir });
+        if expanded_here {
+            entries.extend(build_entries(&path, depth + 1, expanded)?);
+        }
+    }
+    Ok(entries)
+}
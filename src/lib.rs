@@ -0,0 +1,9 @@
+pub mod buffer;
+pub mod crdt;
+pub mod cursor;
+pub mod event;
+pub mod export;
+pub mod file_tree;
+pub mod markdown;
+pub mod piece_table;
+pub mod state;
@@ -0,0 +1,71 @@
+//! CommonMark pull-parsing for compose mode.
+//!
+//! [`parser::MarkdownParser`] walks buffer text with `pulldown-cmark` and
+//! flattens its event stream into [`SpannedEvent`]s that carry the source
+//! byte range of every construct. [`render`] turns that stream into
+//! [`render::StyledSpan`]s keyed by byte range so the renderer can map
+//! styling back onto `Buffer::byte_to_line`/`line_to_byte` for viewport
+//! rendering, instead of the substring matching compose mode used before.
+
+pub mod parser;
+pub mod render;
+
+use std::ops::Range;
+
+/// A block- or inline-level construct bracketed by a `Start`/`End` pair.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tag {
+    Heading(u8),
+    List(Option<u64>),
+    Item,
+    BlockQuote,
+    /// Fenced code blocks carry their info string (e.g. `rust`); indented
+    /// code blocks carry `None`.
+    CodeBlock(Option<String>),
+    Emphasis,
+    Strong,
+    Strikethrough,
+    Link { dest: String },
+}
+
+/// One node of the flat markdown event stream.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkdownEvent {
+    Start(Tag),
+    End(Tag),
+    Text(String),
+    InlineCode(String),
+    SoftBreak,
+    HardBreak,
+    TaskListMarker(bool),
+}
+
+/// A [`MarkdownEvent`] paired with the byte range in the source text it was
+/// parsed from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpannedEvent {
+    pub event: MarkdownEvent,
+    pub range: Range<usize>,
+}
+
+/// Which CommonMark extensions the parser should recognize, gating the
+/// fixtures this subsystem was built against (`- [x] Checked task`,
+/// `~~strikethrough~~`, fenced ```rust blocks, `## Features`).
+#[derive(Debug, Clone, Copy)]
+pub struct MarkdownOptions {
+    pub tables: bool,
+    pub task_lists: bool,
+    pub strikethrough: bool,
+    pub footnotes: bool,
+}
+
+impl Default for MarkdownOptions {
+    fn default() -> Self {
+        Self {
+            tables: true,
+            task_lists: true,
+            strikethrough: true,
+            footnotes: false,
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use pulldown_cmark::{
+    CodeBlockKind, Event as CmarkEvent, HeadingLevel, LinkType, Options as CmarkOptions,
+    Parser as CmarkParser, Tag as CmarkTag,
+};
+
+use super::{MarkdownEvent, MarkdownOptions, SpannedEvent, Tag};
+
+/// Runs CommonMark's pull-parser over buffer text and adapts its event
+/// stream into our flat, spanned sequence. Every event keeps the source
+/// byte offsets `pulldown_cmark`'s `into_offset_iter` reports, so styling
+/// maps back onto the originating `Buffer` without a second parse pass.
+pub struct MarkdownParser<'a> {
+    text: &'a str,
+    options: MarkdownOptions,
+}
+
+impl<'a> MarkdownParser<'a> {
+    pub fn new(text: &'a str, options: MarkdownOptions) -> Self {
+        Self { text, options }
+    }
+
+    /// Parse the full text into a flat, spanned event stream. Soft breaks
+    /// are kept distinct from hard breaks here; it's the renderer's job to
+    /// collapse soft breaks to spaces and force newlines on hard breaks.
+    pub fn parse(&self) -> Vec<SpannedEvent> {
+        let parser = CmarkParser::new_ext(self.text, self.cmark_options());
+        let mut events = Vec::new();
+
+        for (event, range) in parser.into_offset_iter() {
+            match event {
+                CmarkEvent::Start(tag) => {
+                    if let Some(tag) = convert_tag(tag) {
+                        events.push(SpannedEvent { event: MarkdownEvent::Start(tag), range });
+                    }
+                }
+                CmarkEvent::End(tag) => {
+                    if let Some(tag) = convert_tag(tag) {
+                        events.push(SpannedEvent { event: MarkdownEvent::End(tag), range });
+                    }
+                }
+                CmarkEvent::Text(text) => {
+                    events.push(SpannedEvent {
+                        event: MarkdownEvent::Text(text.into_string()),
+                        range,
+                    });
+                }
+                CmarkEvent::Code(text) => {
+                    events.push(SpannedEvent {
+                        event: MarkdownEvent::InlineCode(text.into_string()),
+                        range,
+                    });
+                }
+                CmarkEvent::SoftBreak => {
+                    events.push(SpannedEvent { event: MarkdownEvent::SoftBreak, range });
+                }
+                CmarkEvent::HardBreak => {
+                    events.push(SpannedEvent { event: MarkdownEvent::HardBreak, range });
+                }
+                CmarkEvent::TaskListMarker(checked) => {
+                    events.push(SpannedEvent {
+                        event: MarkdownEvent::TaskListMarker(checked),
+                        range,
+                    });
+                }
+                // Rule, HTML blocks, images and footnotes aren't styled by
+                // compose mode yet; drop them rather than emit a span the
+                // renderer doesn't know what to do with.
+                _ => {}
+            }
+        }
+
+        events
+    }
+
+    fn cmark_options(&self) -> CmarkOptions {
+        let mut options = CmarkOptions::empty();
+        if self.options.tables {
+            options.insert(CmarkOptions::ENABLE_TABLES);
+        }
+        if self.options.task_lists {
+            options.insert(CmarkOptions::ENABLE_TASKLISTS);
+        }
+        if self.options.strikethrough {
+            options.insert(CmarkOptions::ENABLE_STRIKETHROUGH);
+        }
+        if self.options.footnotes {
+            options.insert(CmarkOptions::ENABLE_FOOTNOTES);
+        }
+        options
+    }
+}
+
+fn convert_tag(tag: CmarkTag) -> Option<Tag> {
+    match tag {
+        CmarkTag::Heading(level, ..) => Some(Tag::Heading(heading_level(level))),
+        CmarkTag::List(start) => Some(Tag::List(start)),
+        CmarkTag::Item => Some(Tag::Item),
+        CmarkTag::BlockQuote => Some(Tag::BlockQuote),
+        CmarkTag::CodeBlock(CodeBlockKind::Fenced(info)) => {
+            let info = info.trim();
+            Some(Tag::CodeBlock(if info.is_empty() { None } else { Some(info.to_string()) }))
+        }
+        CmarkTag::CodeBlock(CodeBlockKind::Indented) => Some(Tag::CodeBlock(None)),
+        CmarkTag::Emphasis => Some(Tag::Emphasis),
+        CmarkTag::Strong => Some(Tag::Strong),
+        CmarkTag::Strikethrough => Some(Tag::Strikethrough),
+        CmarkTag::Link(LinkType::Inline, dest, _) | CmarkTag::Link(LinkType::Reference, dest, _) => {
+            Some(Tag::Link { dest: dest.into_string() })
+        }
+        _ => None,
+    }
+}
+
+fn heading_level(level: HeadingLevel) -> u8 {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
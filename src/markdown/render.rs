@@ -0,0 +1,98 @@
+use std::ops::Range;
+
+use crate::buffer::Buffer;
+
+use super::parser::MarkdownParser;
+use super::{MarkdownEvent, MarkdownOptions, Tag};
+
+/// The visual treatment compose mode applies to a byte range of the
+/// buffer. Kept separate from [`Tag`] so the renderer doesn't need to
+/// reconstruct Start/End pairing just to know what to draw.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkdownStyle {
+    Heading(u8),
+    ListItem,
+    BlockQuote,
+    CodeBlock { language: Option<String> },
+    Emphasis,
+    Strong,
+    Strikethrough,
+    InlineCode,
+    Link,
+    TaskMarker(bool),
+}
+
+/// A style applied to a contiguous byte range of the buffer, ready to be
+/// mapped through `Buffer::byte_to_line`/`line_to_byte` for viewport
+/// rendering.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyledSpan {
+    pub range: Range<usize>,
+    pub style: MarkdownStyle,
+}
+
+/// Parses `buffer`'s full text and converts the resulting event stream
+/// into styled spans keyed by byte range.
+pub fn styled_spans(buffer: &Buffer, options: MarkdownOptions) -> Vec<StyledSpan> {
+    spans_for_text(&buffer.to_string(), options)
+}
+
+/// Same as [`styled_spans`] but over raw text, for callers (export, tests)
+/// that don't have a live `Buffer` to hand.
+pub fn spans_for_text(text: &str, options: MarkdownOptions) -> Vec<StyledSpan> {
+    let events = MarkdownParser::new(text, options).parse();
+    let mut spans = Vec::new();
+    // Start events open a span at the tag's full range (which already
+    // covers its matching End in pulldown-cmark's offset tracking), so we
+    // only need to look at Start/leaf events to build the styled list.
+    for spanned in &events {
+        let style = match &spanned.event {
+            MarkdownEvent::Start(Tag::Heading(level)) => Some(MarkdownStyle::Heading(*level)),
+            MarkdownEvent::Start(Tag::Item) => Some(MarkdownStyle::ListItem),
+            MarkdownEvent::Start(Tag::BlockQuote) => Some(MarkdownStyle::BlockQuote),
+            MarkdownEvent::Start(Tag::CodeBlock(language)) => {
+                Some(MarkdownStyle::CodeBlock { language: language.clone() })
+            }
+            MarkdownEvent::Start(Tag::Emphasis) => Some(MarkdownStyle::Emphasis),
+            MarkdownEvent::Start(Tag::Strong) => Some(MarkdownStyle::Strong),
+            MarkdownEvent::Start(Tag::Strikethrough) => Some(MarkdownStyle::Strikethrough),
+            MarkdownEvent::Start(Tag::Link { .. }) => Some(MarkdownStyle::Link),
+            MarkdownEvent::InlineCode(_) => Some(MarkdownStyle::InlineCode),
+            MarkdownEvent::TaskListMarker(checked) => Some(MarkdownStyle::TaskMarker(*checked)),
+            _ => None,
+        };
+
+        if let Some(style) = style {
+            spans.push(StyledSpan { range: spanned.range.clone(), style });
+        }
+    }
+
+    spans
+}
+
+/// Re-lays out `text` for compose mode's soft-wrapped paragraphs: soft
+/// breaks collapse to a single space, hard breaks force a newline, and
+/// everything else passes through untouched.
+pub fn reflow_for_compose(text: &str, options: MarkdownOptions) -> String {
+    let events = MarkdownParser::new(text, options).parse();
+    let mut out = String::with_capacity(text.len());
+    let mut last_end = 0;
+
+    for spanned in &events {
+        match &spanned.event {
+            MarkdownEvent::SoftBreak => {
+                out.push_str(&text[last_end..spanned.range.start]);
+                out.push(' ');
+                last_end = spanned.range.end;
+            }
+            MarkdownEvent::HardBreak => {
+                out.push_str(&text[last_end..spanned.range.start]);
+                out.push('\n');
+                last_end = spanned.range.end;
+            }
+            _ => {}
+        }
+    }
+    out.push_str(&text[last_end..]);
+    out
+}
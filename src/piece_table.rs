@@ -0,0 +1,152 @@
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    Original,
+    Added,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Piece {
+    source: Source,
+    start: usize,
+    len: usize,
+}
+
+/// A piece table: the original text plus an append-only "added" buffer,
+/// with the document represented as a sequence of slices (`Piece`s) into
+/// one or the other. Editing only ever splits or re-slices entries in the
+/// (small) piece list — it never copies the document text itself, unlike
+/// a flat `String` where every insert/delete shifts everything after it.
+#[derive(Debug, Clone)]
+pub struct PieceTable {
+    original: String,
+    added: String,
+    pieces: Vec<Piece>,
+    len: usize,
+}
+
+impl PieceTable {
+    pub fn new() -> Self {
+        Self::from_text("")
+    }
+
+    /// Named `from_text` rather than `from_str` since this isn't `FromStr`
+    /// (it's infallible and takes `&str` directly) — `from_str` would trip
+    /// `clippy::should_implement_trait`.
+    pub fn from_text(text: &str) -> Self {
+        let pieces = if text.is_empty() {
+            Vec::new()
+        } else {
+            vec![Piece { source: Source::Original, start: 0, len: text.len() }]
+        };
+        Self { original: text.to_string(), added: String::new(), pieces, len: text.len() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Materialize the full document text. This is the one O(n) operation
+    /// in this module — callers that only need to inspect a range should
+    /// slice the result rather than call this per edit.
+    pub fn text(&self) -> String {
+        let mut out = String::with_capacity(self.len);
+        for piece in &self.pieces {
+            out.push_str(self.slice(piece));
+        }
+        out
+    }
+
+    pub fn insert(&mut self, position: usize, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+
+        let added_start = self.added.len();
+        self.added.push_str(text);
+        let new_piece = Piece { source: Source::Added, start: added_start, len: text.len() };
+
+        let mut offset = 0usize;
+        for idx in 0..self.pieces.len() {
+            let piece = self.pieces[idx];
+            if position < offset + piece.len {
+                let split = position - offset;
+                if split == 0 {
+                    self.pieces.insert(idx, new_piece);
+                } else {
+                    let left = Piece { source: piece.source, start: piece.start, len: split };
+                    let right = Piece {
+                        source: piece.source,
+                        start: piece.start + split,
+                        len: piece.len - split,
+                    };
+                    self.pieces.splice(idx..idx + 1, [left, new_piece, right]);
+                }
+                self.len += text.len();
+                return;
+            }
+            offset += piece.len;
+        }
+
+        // `position` is at (or past) the end of the document.
+        self.pieces.push(new_piece);
+        self.len += text.len();
+    }
+
+    pub fn delete(&mut self, range: Range<usize>) {
+        if range.start == range.end {
+            return;
+        }
+
+        let mut new_pieces = Vec::with_capacity(self.pieces.len());
+        let mut offset = 0usize;
+
+        for piece in &self.pieces {
+            let piece_start = offset;
+            let piece_end = offset + piece.len;
+            offset = piece_end;
+
+            if piece_end <= range.start || piece_start >= range.end {
+                new_pieces.push(*piece);
+                continue;
+            }
+            if piece_start < range.start {
+                new_pieces.push(Piece {
+                    source: piece.source,
+                    start: piece.start,
+                    len: range.start - piece_start,
+                });
+            }
+            if piece_end > range.end {
+                let skip = range.end - piece_start;
+                new_pieces.push(Piece {
+                    source: piece.source,
+                    start: piece.start + skip,
+                    len: piece.len - skip,
+                });
+            }
+        }
+
+        self.pieces = new_pieces;
+        self.len -= range.end - range.start;
+    }
+
+    fn slice(&self, piece: &Piece) -> &str {
+        let source = match piece.source {
+            Source::Original => &self.original,
+            Source::Added => &self.added,
+        };
+        &source[piece.start..piece.start + piece.len]
+    }
+}
+
+impl Default for PieceTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
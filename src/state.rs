@@ -0,0 +1,57 @@
+use std::path::{Path, PathBuf};
+
+use crate::buffer::Buffer;
+use crate::cursor::Cursors;
+use crate::event::Event;
+
+/// The full state of one open document: its buffer, cursors, the path it
+/// was loaded from (if any), and the viewport size compose mode and other
+/// subsystems render into.
+#[derive(Debug, Clone)]
+pub struct EditorState {
+    pub buffer: Buffer,
+    pub cursors: Cursors,
+    pub path: Option<PathBuf>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl EditorState {
+    pub fn new(buffer: Buffer, width: usize, height: usize) -> Self {
+        Self { buffer, cursors: Cursors::new(), path: None, width, height }
+    }
+
+    /// Replace this document's buffer with the contents of `path`,
+    /// resetting cursors to the start. This is the single path every
+    /// "open a file" action routes through, whether from the command
+    /// palette, the file-tree explorer, or a test harness.
+    pub fn open_file(&mut self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        self.buffer = Buffer::from_str(&text);
+        self.cursors = Cursors::new();
+        self.path = Some(path.to_path_buf());
+        Ok(())
+    }
+
+    /// Apply an event to the buffer and rebase every cursor against it.
+    pub fn apply(&mut self, event: &Event) {
+        match event {
+            Event::Insert { position, text, .. } => {
+                self.buffer.insert(*position, text);
+                self.cursors.adjust_after_insert(*position, text.len());
+            }
+            Event::Delete { range, .. } => {
+                let len = range.end - range.start;
+                self.buffer.delete(range.clone());
+                self.cursors.adjust_after_delete(range.start..range.start + len);
+            }
+            Event::MoveCursor { cursor_id, position, anchor } => {
+                if let Some(cursor) = self.cursors.get_mut(*cursor_id) {
+                    cursor.position = *position;
+                    cursor.anchor = *anchor;
+                }
+            }
+        }
+    }
+}
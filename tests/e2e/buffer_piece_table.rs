@@ -0,0 +1,92 @@
+use editor::buffer::Buffer;
+
+/// Test that inserting and deleting mid-document keeps the line cache in
+/// sync with the piece-table-backed text, the way the old flat-`String`
+/// buffer's full rebuild used to guarantee.
+#[test]
+fn test_insert_delete_keeps_lines_in_sync() {
+    let mut buffer = Buffer::from_str("line one\nline two\nline three\n");
+
+    buffer.insert(9, "inserted\n");
+    assert_eq!(buffer.to_string(), "line one\ninserted\nline two\nline three\n");
+    assert_eq!(buffer.byte_to_line(9), 1);
+    assert_eq!(buffer.line_to_byte(1), 9);
+    assert_eq!(buffer.line_to_byte(2), 18);
+
+    buffer.delete(9..18);
+    assert_eq!(buffer.to_string(), "line one\nline two\nline three\n");
+    assert_eq!(buffer.line_to_byte(1), 9);
+}
+
+/// Test that a delete spanning multiple newlines collapses the affected
+/// lines correctly.
+#[test]
+fn test_delete_spanning_multiple_lines() {
+    let mut buffer = Buffer::from_str("aaa\nbbb\nccc\nddd\n");
+    // Delete "bbb\nccc\n" entirely, leaving "aaa\nddd\n".
+    buffer.delete(4..12);
+
+    assert_eq!(buffer.to_string(), "aaa\nddd\n");
+    assert_eq!(buffer.line_to_byte(0), 0);
+    assert_eq!(buffer.line_to_byte(1), 4);
+    assert_eq!(buffer.byte_to_line(4), 1);
+}
+
+/// Test that deleting a single newline joins two lines, rather than
+/// leaving a stale line start behind (a delete whose end, but not start,
+/// lands on a line boundary).
+#[test]
+fn test_delete_join_lines() {
+    let mut buffer = Buffer::from_str("A\nB\nC");
+    // Delete just the "\n" between "A" and "B".
+    buffer.delete(1..2);
+
+    assert_eq!(buffer.to_string(), "AB\nC");
+    assert_eq!(buffer.line_to_byte(0), 0);
+    assert_eq!(buffer.line_to_byte(1), 3);
+    assert_eq!(buffer.byte_to_line(0), 0);
+    assert_eq!(buffer.byte_to_line(3), 1);
+}
+
+/// Test deleting the first line including its trailing newline (a delete
+/// whose start and end both land on line boundaries).
+#[test]
+fn test_delete_first_line_including_newline() {
+    let mut buffer = Buffer::from_str("hello\nworld\n");
+    buffer.delete(0..6);
+
+    assert_eq!(buffer.to_string(), "world\n");
+    assert_eq!(buffer.line_to_byte(0), 0);
+    assert_eq!(buffer.line_to_byte(1), 6);
+    assert_eq!(buffer.byte_to_line(0), 0);
+    assert_eq!(buffer.byte_to_line(6), 1);
+}
+
+/// Test inserting and deleting at the very start and end of the buffer,
+/// where piece splitting degenerates to push/insert-at-index-0.
+#[test]
+fn test_edits_at_buffer_boundaries() {
+    let mut buffer = Buffer::from_str("middle");
+    buffer.insert(0, "start-");
+    buffer.insert(buffer.len(), "-end");
+    assert_eq!(buffer.to_string(), "start-middle-end");
+
+    buffer.delete(0..6);
+    assert_eq!(buffer.to_string(), "middle-end");
+}
+
+/// Test that repeated small edits interleaved across the document match
+/// what a flat-`String` buffer would have produced, guarding against
+/// piece-table bookkeeping drift.
+#[test]
+fn test_repeated_interleaved_edits_match_expected_text() {
+    let mut buffer = Buffer::from_str("0123456789");
+    buffer.insert(5, "AB");
+    buffer.delete(0..2);
+    buffer.insert(buffer.len(), "Z");
+    buffer.delete(3..5);
+
+    // "0123456789" -> "01234AB56789" -> "234AB56789" -> "234AB56789Z"
+    // -> delete[3..5] ("AB") -> "23456789Z"
+    assert_eq!(buffer.to_string(), "23456789Z");
+}
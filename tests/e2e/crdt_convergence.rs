@@ -0,0 +1,196 @@
+use editor::crdt::{Crdt, RemoteOp};
+
+/// Apply every op in `ops` to a fresh `Crdt` for site `site_id` and
+/// return its resulting visible text.
+fn converge(site_id: u64, ops: &[RemoteOp]) -> String {
+    let mut crdt = Crdt::new(site_id);
+    for op in ops {
+        crdt.apply_remote(op.clone());
+    }
+    crdt.text()
+}
+
+/// Test that two sites typing into the same document converge to the same
+/// text whichever site's block of ops arrives first.
+#[test]
+fn test_concurrent_inserts_converge() {
+    let mut a = Crdt::new(1);
+    let ops_a = a.local_insert(0, "hello");
+
+    let mut b = Crdt::new(2);
+    let ops_b = b.local_insert(0, "world");
+
+    let mut forward = Vec::new();
+    forward.extend(ops_a.iter().cloned());
+    forward.extend(ops_b.iter().cloned());
+
+    let mut backward = Vec::new();
+    backward.extend(ops_b.iter().cloned());
+    backward.extend(ops_a.iter().cloned());
+
+    assert_eq!(converge(99, &forward), converge(99, &backward));
+}
+
+/// Test that a delete applied before its insert has arrived still
+/// produces the same final text as applying them in causal order.
+#[test]
+fn test_delete_before_insert_arrival() {
+    let mut origin = Crdt::new(1);
+    let insert_ops = origin.local_insert(0, "abc");
+    let delete_ops = origin.local_delete(1, 2); // delete "b"
+
+    let mut causal = Crdt::new(2);
+    for op in insert_ops.iter().chain(delete_ops.iter()) {
+        causal.apply_remote(op.clone());
+    }
+
+    let mut reversed = Crdt::new(3);
+    for op in delete_ops.iter().chain(insert_ops.iter()) {
+        reversed.apply_remote(op.clone());
+    }
+
+    assert_eq!(causal.text(), "ac");
+    assert_eq!(causal.text(), reversed.text());
+}
+
+/// Test that applying a shuffled batch of ops from several sites to
+/// multiple fresh replicas always produces identical final text.
+#[test]
+fn test_shuffled_batches_converge_across_replicas() {
+    let mut site_a = Crdt::new(1);
+    let a_ops = site_a.local_insert(0, "rust");
+
+    let mut site_b = Crdt::new(2);
+    let b_ops = site_b.local_insert(0, "lang");
+
+    let mut site_c = Crdt::new(3);
+    let c_ops = site_c.local_insert(0, "crdt");
+
+    let orderings: Vec<Vec<RemoteOp>> = vec![
+        a_ops.iter().chain(b_ops.iter()).chain(c_ops.iter()).cloned().collect(),
+        c_ops.iter().chain(a_ops.iter()).chain(b_ops.iter()).cloned().collect(),
+        b_ops.iter().chain(c_ops.iter()).chain(a_ops.iter()).cloned().collect(),
+    ];
+
+    let texts: Vec<String> =
+        orderings.iter().enumerate().map(|(i, ops)| converge(100 + i as u64, ops)).collect();
+
+    assert!(texts.windows(2).all(|pair| pair[0] == pair[1]));
+}
+
+/// Exhaustively generate every permutation of `items` (Heap's algorithm),
+/// for tests that need to prove convergence across *every* possible
+/// delivery order rather than a handful of hand-picked ones.
+fn permutations<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut items = items.to_vec();
+    let mut result = Vec::new();
+    let n = items.len();
+    let mut c = vec![0usize; n];
+    result.push(items.clone());
+    let mut i = 0;
+    while i < n {
+        if c[i] < i {
+            if i % 2 == 0 {
+                items.swap(0, i);
+            } else {
+                items.swap(c[i], i);
+            }
+            result.push(items.clone());
+            c[i] += 1;
+            i = 0;
+        } else {
+            c[i] = 0;
+            i += 1;
+        }
+    }
+    result
+}
+
+/// Test that two concurrent multi-character inserts converge under *any*
+/// op-level delivery order, including ones where an insert arrives
+/// before its own `left_origin` has landed — not just orderings that
+/// happen to keep each site's ops causally grouped together.
+#[test]
+fn test_op_level_shuffle_of_concurrent_inserts_converges() {
+    let mut a = Crdt::new(1);
+    let ops_a = a.local_insert(0, "abc");
+
+    let mut b = Crdt::new(2);
+    let ops_b = b.local_insert(0, "xyz");
+
+    let mut combined = ops_a.clone();
+    combined.extend(ops_b.iter().cloned());
+
+    let expected = converge(0, &combined);
+
+    for (i, ordering) in permutations(&combined).into_iter().enumerate() {
+        assert_eq!(converge(100 + i as u64, &ordering), expected, "ordering {ordering:?} diverged");
+    }
+}
+
+/// Test that anchoring and deleting resolve multi-byte characters by
+/// their byte width, not by character count, since `position` is always
+/// a visible *byte* offset into the same space `Buffer::insert`/`delete`
+/// use.
+#[test]
+fn test_byte_offset_mapping_on_multi_byte_text() {
+    let mut crdt = Crdt::new(1);
+    let ops = crdt.local_insert(0, "éb"); // é is 2 bytes, b is 1 byte
+
+    // Byte offset 2 sits right after "é" (not after "éb", which char
+    // counting would mistake it for).
+    let e_id = match &ops[0] {
+        RemoteOp::Insert { id, .. } => *id,
+        _ => unreachable!(),
+    };
+    assert_eq!(crdt.anchor_for(2), Some(e_id));
+
+    // Deleting byte range 0..2 removes only "é", leaving "b".
+    crdt.local_delete(0, 2);
+    assert_eq!(crdt.text(), "b");
+}
+
+/// Test that re-applying an already-applied insert is a no-op, so
+/// duplicate delivery from an unreliable transport doesn't duplicate
+/// characters.
+#[test]
+fn test_apply_remote_is_idempotent() {
+    let mut crdt = Crdt::new(1);
+    let ops = crdt.local_insert(0, "hi");
+
+    for op in &ops {
+        crdt.apply_remote(op.clone());
+        crdt.apply_remote(op.clone());
+    }
+
+    assert_eq!(crdt.text(), "hi");
+}
+
+/// Test that a cursor anchored to an element survives a concurrent
+/// remote insert before it, rebasing to the correct new visible offset.
+#[test]
+fn test_cursor_rebases_after_concurrent_insert() {
+    use editor::cursor::Cursors;
+
+    let mut crdt = Crdt::new(1);
+    let ops = crdt.local_insert(0, "bd");
+
+    let mut cursors = Cursors::new();
+    let cursor_id = cursors.primary_id();
+    cursors.get_mut(cursor_id).unwrap().position = 2; // after "bd"
+    let anchor = crdt.anchor_for(2);
+
+    // A remote site inserts "c" between "b" and "d" concurrently.
+    let mut remote = Crdt::new(2);
+    for op in &ops {
+        remote.apply_remote(op.clone());
+    }
+    let remote_ops = remote.local_insert(1, "c");
+    for op in &remote_ops {
+        crdt.apply_remote(op.clone());
+    }
+
+    assert_eq!(crdt.text(), "bcd");
+    crdt.rebase_cursors(&mut cursors, &[(cursor_id, anchor)]);
+    assert_eq!(cursors.get(cursor_id).unwrap().position, 3);
+}
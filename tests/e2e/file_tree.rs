@@ -0,0 +1,122 @@
+use std::fs;
+use std::path::PathBuf;
+
+use editor::buffer::Buffer;
+use editor::file_tree::FileTree;
+use editor::state::EditorState;
+
+/// Build a scratch project under the system temp dir:
+///
+/// ```text
+/// root/
+///   docs/
+///     notes.md
+///   main.rs
+/// ```
+fn scratch_project(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("fresh_file_tree_test_{name}"));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("docs")).unwrap();
+    fs::write(root.join("docs").join("notes.md"), "# Notes\n").unwrap();
+    fs::write(root.join("main.rs"), "fn main() {}\n").unwrap();
+    root
+}
+
+/// Test that a freshly-opened tree lists the root's immediate children,
+/// directories first, without recursing into unexpanded folders.
+#[test]
+fn test_lazy_listing_shows_top_level_only() {
+    let root = scratch_project("lazy_listing");
+    let tree = FileTree::new(&root).unwrap();
+
+    let names: Vec<_> =
+        tree.entries().iter().map(|e| e.path.file_name().unwrap().to_str().unwrap()).collect();
+    assert_eq!(names, vec!["docs", "main.rs"]);
+}
+
+/// Test that expanding a directory reveals its children, and collapsing
+/// it hides them again.
+#[test]
+fn test_expand_and_collapse() {
+    let root = scratch_project("expand_collapse");
+    let mut tree = FileTree::new(&root).unwrap();
+
+    // "docs" is selected by default (directories sort first).
+    assert!(tree.selected_entry().unwrap().is_dir);
+    tree.toggle_selected().unwrap();
+
+    let names: Vec<_> =
+        tree.entries().iter().map(|e| e.path.file_name().unwrap().to_str().unwrap()).collect();
+    assert_eq!(names, vec!["docs", "notes.md", "main.rs"]);
+
+    tree.toggle_selected().unwrap();
+    let names: Vec<_> =
+        tree.entries().iter().map(|e| e.path.file_name().unwrap().to_str().unwrap()).collect();
+    assert_eq!(names, vec!["docs", "main.rs"]);
+}
+
+/// Test keyboard navigation moves the selection down and up across
+/// entries without touching the viewport scroll until needed.
+#[test]
+fn test_keyboard_navigation() {
+    let root = scratch_project("keyboard_navigation");
+    let mut tree = FileTree::new(&root).unwrap();
+
+    assert_eq!(tree.selected_index(), 0);
+    tree.select_next(10);
+    assert_eq!(tree.selected_index(), 1);
+    tree.select_previous(10);
+    assert_eq!(tree.selected_index(), 0);
+
+    // Selecting previous at the top is a no-op, not a panic.
+    tree.select_previous(10);
+    assert_eq!(tree.selected_index(), 0);
+    assert_eq!(tree.scroll_offset(), 0);
+}
+
+/// Test that "open selected" on a file routes through
+/// `EditorState::open_file`, the same path the markdown tests use via
+/// `EditorTestHarness::open_file`.
+#[test]
+fn test_open_selected_file_loads_into_editor_state() {
+    let root = scratch_project("open_selected");
+    let mut tree = FileTree::new(&root).unwrap();
+    tree.select_next(10); // move from "docs" to "main.rs"
+
+    let mut state = EditorState::new(Buffer::new(), 80, 24);
+    tree.open_selected(&mut state).unwrap();
+
+    assert_eq!(state.buffer.to_string(), "fn main() {}\n");
+    assert_eq!(state.path, Some(root.join("main.rs")));
+}
+
+/// Test that "open selected" on a directory toggles it instead of
+/// erroring, since directories aren't directly openable as documents.
+#[test]
+fn test_open_selected_directory_toggles_expansion() {
+    let root = scratch_project("open_selected_dir");
+    let mut tree = FileTree::new(&root).unwrap();
+
+    let mut state = EditorState::new(Buffer::new(), 80, 24);
+    tree.open_selected(&mut state).unwrap();
+
+    let names: Vec<_> =
+        tree.entries().iter().map(|e| e.path.file_name().unwrap().to_str().unwrap()).collect();
+    assert_eq!(names, vec!["docs", "notes.md", "main.rs"]);
+    assert_eq!(state.path, None);
+}
+
+/// Test that refreshing after a filesystem change under the watched root
+/// picks up new entries.
+#[test]
+fn test_refresh_picks_up_filesystem_changes() {
+    let root = scratch_project("refresh");
+    let mut tree = FileTree::new(&root).unwrap();
+
+    fs::write(root.join("new_file.txt"), "hi\n").unwrap();
+    tree.refresh().unwrap();
+
+    let names: Vec<_> =
+        tree.entries().iter().map(|e| e.path.file_name().unwrap().to_str().unwrap()).collect();
+    assert_eq!(names, vec!["docs", "main.rs", "new_file.txt"]);
+}
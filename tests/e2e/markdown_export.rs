@@ -0,0 +1,80 @@
+use std::path::PathBuf;
+
+use editor::export::to_html_fragment;
+use editor::markdown::MarkdownOptions;
+
+fn fixture_text() -> String {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let path = PathBuf::from(manifest_dir)
+        .join("tests")
+        .join("fixtures")
+        .join("markdown_sample.md");
+    std::fs::read_to_string(path).unwrap()
+}
+
+/// Test that the top-level heading exports as `<h1>`.
+#[test]
+fn test_export_heading() {
+    let html = to_html_fragment(&fixture_text(), MarkdownOptions::default());
+    assert!(html.contains("<h1>Markdown Compose Mode Test</h1>"));
+}
+
+/// Test that the feature list exports as a `<ul>`.
+#[test]
+fn test_export_unordered_list() {
+    let html = to_html_fragment(&fixture_text(), MarkdownOptions::default());
+    assert!(html.contains("<ul>"));
+    assert!(html.contains("</ul>"));
+}
+
+/// Test that the fenced Rust code block exports with a language class.
+#[test]
+fn test_export_fenced_code_block() {
+    let html = to_html_fragment(&fixture_text(), MarkdownOptions::default());
+    assert!(html.contains("<pre><code class=\"language-rust\">"));
+    assert!(html.contains("fn main()"));
+}
+
+/// Test that strikethrough text exports as `<del>`.
+#[test]
+fn test_export_strikethrough() {
+    let html = to_html_fragment(&fixture_text(), MarkdownOptions::default());
+    assert!(html.contains("<del>strikethrough</del>"));
+}
+
+/// Test that task list markers export as disabled checkboxes with the
+/// right checked state.
+#[test]
+fn test_export_task_list_markers() {
+    let html = to_html_fragment(&fixture_text(), MarkdownOptions::default());
+    assert!(html.contains("<input type=\"checkbox\" disabled checked />"));
+    assert!(html.contains("<input type=\"checkbox\" disabled />"));
+}
+
+/// Test that a self-contained document wraps the fragment with inlined
+/// CSS and a title.
+#[test]
+fn test_export_document_wraps_fragment() {
+    use editor::export::to_html_document;
+
+    let doc = to_html_document("Sample", &fixture_text(), MarkdownOptions::default());
+    assert!(doc.starts_with("<!DOCTYPE html>"));
+    assert!(doc.contains("<title>Sample</title>"));
+    assert!(doc.contains("<style>"));
+    assert!(doc.contains("<h1>Markdown Compose Mode Test</h1>"));
+}
+
+/// Test that the headless `EditorState` API produces the same output as
+/// the free function, for callers that only have a live document.
+#[test]
+fn test_editor_state_export_matches_free_function() {
+    use editor::buffer::Buffer;
+    use editor::state::EditorState;
+
+    let text = fixture_text();
+    let state = EditorState::new(Buffer::from_str(&text), 80, 24);
+
+    let via_state = state.export_html_fragment(MarkdownOptions::default());
+    let via_function = to_html_fragment(&text, MarkdownOptions::default());
+    assert_eq!(via_state, via_function);
+}
@@ -0,0 +1,125 @@
+use std::path::PathBuf;
+
+use editor::markdown::render::{spans_for_text, MarkdownStyle};
+use editor::markdown::{MarkdownEvent, MarkdownOptions, Tag};
+
+fn fixture_text() -> String {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let path = PathBuf::from(manifest_dir)
+        .join("tests")
+        .join("fixtures")
+        .join("markdown_sample.md");
+    std::fs::read_to_string(path).unwrap()
+}
+
+/// Test that the top-level heading is parsed with the right level and
+/// byte range, rather than matched as a raw substring.
+#[test]
+fn test_heading_levels_and_spans() {
+    let text = fixture_text();
+    let spans = spans_for_text(&text, MarkdownOptions::default());
+
+    let h1 = spans
+        .iter()
+        .find(|s| s.style == MarkdownStyle::Heading(1))
+        .expect("h1 span");
+    assert_eq!(text[h1.range.clone()].trim_end(), "# Markdown Compose Mode Test");
+
+    assert!(spans.iter().any(|s| s.style == MarkdownStyle::Heading(2)));
+    assert!(spans.iter().any(|s| s.style == MarkdownStyle::Heading(3)));
+}
+
+/// Test that task list markers carry their checked state rather than
+/// being indistinguishable `- [ ]` text.
+#[test]
+fn test_task_list_markers() {
+    let text = fixture_text();
+    let spans = spans_for_text(&text, MarkdownOptions::default());
+
+    assert!(spans.iter().any(|s| s.style == MarkdownStyle::TaskMarker(false)));
+    assert!(spans.iter().any(|s| s.style == MarkdownStyle::TaskMarker(true)));
+}
+
+/// Test that a fenced code block keeps its info string so the renderer
+/// (and the HTML exporter) can attach a `language-*` class.
+#[test]
+fn test_fenced_code_block_language() {
+    let text = fixture_text();
+    let spans = spans_for_text(&text, MarkdownOptions::default());
+
+    let code = spans
+        .iter()
+        .find(|s| matches!(&s.style, MarkdownStyle::CodeBlock { .. }))
+        .expect("code block span");
+    assert_eq!(code.style, MarkdownStyle::CodeBlock { language: Some("rust".to_string()) });
+}
+
+/// Test that emphasis, strong, strikethrough and inline code are each
+/// recognized as distinct styles.
+#[test]
+fn test_inline_styles() {
+    let text = fixture_text();
+    let spans = spans_for_text(&text, MarkdownOptions::default());
+
+    assert!(spans.iter().any(|s| s.style == MarkdownStyle::Strong));
+    assert!(spans.iter().any(|s| s.style == MarkdownStyle::Emphasis));
+    assert!(spans.iter().any(|s| s.style == MarkdownStyle::Strikethrough));
+    assert!(spans.iter().any(|s| s.style == MarkdownStyle::InlineCode));
+}
+
+/// Test that block quotes and links are styled with their own tags.
+#[test]
+fn test_block_quote_and_link_spans() {
+    let text = fixture_text();
+    let spans = spans_for_text(&text, MarkdownOptions::default());
+
+    assert!(spans.iter().any(|s| s.style == MarkdownStyle::BlockQuote));
+    assert!(spans.iter().any(|s| s.style == MarkdownStyle::Link));
+}
+
+/// Test that disabling an extension via `MarkdownOptions` turns off its
+/// events, so strikethrough text falls back to plain text spans.
+#[test]
+fn test_strikethrough_can_be_disabled() {
+    let text = fixture_text();
+    let options = MarkdownOptions { strikethrough: false, ..MarkdownOptions::default() };
+    let spans = spans_for_text(&text, options);
+
+    assert!(!spans.iter().any(|s| s.style == MarkdownStyle::Strikethrough));
+}
+
+/// Test that soft breaks collapse to spaces and hard breaks force a
+/// newline when reflowing for compose mode's paragraph re-layout.
+#[test]
+fn test_reflow_collapses_soft_breaks() {
+    use editor::markdown::render::reflow_for_compose;
+
+    let text = "This is a block quote.\nIt spans two lines.";
+    let reflowed = reflow_for_compose(text, MarkdownOptions::default());
+    assert_eq!(reflowed, "This is a block quote. It spans two lines.");
+
+    let hard_break = "line one  \nline two";
+    let reflowed = reflow_for_compose(hard_break, MarkdownOptions::default());
+    assert_eq!(reflowed, "line one\nline two");
+}
+
+/// Test that every event carries a byte range that actually slices the
+/// source text back into something sensible, which is what lets styling
+/// map onto `Buffer::byte_to_line`/`line_to_byte`.
+#[test]
+fn test_event_ranges_are_valid_byte_offsets() {
+    use editor::markdown::parser::MarkdownParser;
+
+    let text = fixture_text();
+    let events = MarkdownParser::new(&text, MarkdownOptions::default()).parse();
+
+    assert!(!events.is_empty());
+    for spanned in &events {
+        assert!(spanned.range.start <= spanned.range.end);
+        assert!(spanned.range.end <= text.len());
+        if matches!(spanned.event, MarkdownEvent::Start(Tag::Heading(_))) {
+            assert!(text.is_char_boundary(spanned.range.start));
+            assert!(text.is_char_boundary(spanned.range.end));
+        }
+    }
+}